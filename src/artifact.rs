@@ -0,0 +1,177 @@
+//! Typed artifact loading from `solc`'s combined-JSON output
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ethabi::Abi;
+use rustc_hex::FromHex;
+use serde::Deserialize;
+
+use crate::error::SolcError;
+
+#[derive(Debug, Deserialize)]
+struct CombinedJsonContract {
+    /// Either a JSON-encoded string (older `solc`) or an inline JSON array
+    /// (current `solc`) — see [`decode_abi`]
+    abi: serde_json::Value,
+    bin: Option<String>,
+    #[serde(rename = "bin-runtime")]
+    bin_runtime: Option<String>,
+    #[serde(rename = "linkReferences", default)]
+    link_references: HashMap<String, HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CombinedJson {
+    contracts: HashMap<String, CombinedJsonContract>,
+}
+
+/// A decoded compile artifact, modeled on ethers-solc's `CompactContract`
+#[derive(Debug)]
+pub struct Contract {
+    pub abi: Abi,
+    pub bytecode: Option<Vec<u8>>,
+    pub deployed_bytecode: Option<Vec<u8>>,
+    /// file -> names of libraries still needing an address before linking
+    pub link_references: HashMap<String, Vec<String>>,
+}
+
+/// Load a single contract named `name` out of a `solc --combined-json` file
+/// at `path`. `name` matches the contract part of solc's `file:Contract` key.
+pub fn load(path: &Path, name: &str) -> Result<Contract, SolcError> {
+    let contents = fs::read_to_string(path).map_err(|source| SolcError::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+
+    let combined: CombinedJson =
+        serde_json::from_str(&contents).map_err(|e| SolcError::Json {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?;
+
+    let suffix = format!(":{}", name);
+    let entry = combined
+        .contracts
+        .iter()
+        .find(|(key, _)| key.ends_with(suffix.as_str()))
+        .map(|(_, contract)| contract)
+        .ok_or_else(|| SolcError::Link(format!("No contract named {} in {:?}", name, path)))?;
+
+    let abi = decode_abi(path, &entry.abi)?;
+
+    let bytecode = entry
+        .bin
+        .as_deref()
+        .map(|hex| decode_bytecode(path, hex))
+        .transpose()?;
+    let deployed_bytecode = entry
+        .bin_runtime
+        .as_deref()
+        .map(|hex| decode_bytecode(path, hex))
+        .transpose()?;
+
+    let link_references = entry
+        .link_references
+        .iter()
+        .map(|(file, libs)| (file.clone(), libs.keys().cloned().collect()))
+        .collect();
+
+    Ok(Contract {
+        abi,
+        bytecode,
+        deployed_bytecode,
+        link_references,
+    })
+}
+
+/// Parse a combined-JSON `abi` field, accepting both the JSON-encoded
+/// string older `solc` emits and the inline JSON array/object current
+/// `solc` emits
+fn decode_abi(path: &Path, value: &serde_json::Value) -> Result<Abi, SolcError> {
+    let abi = match value {
+        serde_json::Value::String(encoded) => serde_json::from_str(encoded),
+        inline => serde_json::from_value(inline.clone()),
+    };
+    abi.map_err(|e| SolcError::Json {
+        path: path.to_owned(),
+        message: e.to_string(),
+    })
+}
+
+fn decode_bytecode(path: &Path, hex: &str) -> Result<Vec<u8>, SolcError> {
+    hex.from_hex().map_err(|source| SolcError::HexDecode {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    fn write_combined_json(contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "solc-rs-test-{:?}-combined.json",
+            std::thread::current().id()
+        ));
+        let mut file = fs::File::create(&path).expect("Could not create test fixture");
+        file.write_all(contents.as_bytes())
+            .expect("Could not write test fixture");
+        path
+    }
+
+    #[test]
+    fn loads_abi_and_bytecode() {
+        let path = write_combined_json(
+            r#"{
+                "contracts": {
+                    "Test.sol:Test": {
+                        "abi": "[]",
+                        "bin": "6080",
+                        "bin-runtime": "6080",
+                        "linkReferences": {}
+                    }
+                }
+            }"#,
+        );
+
+        let contract = load(&path, "Test").expect("Could not load contract");
+        assert_eq!(contract.bytecode, Some(vec![0x60, 0x80]));
+        assert_eq!(contract.deployed_bytecode, Some(vec![0x60, 0x80]));
+        assert!(contract.link_references.is_empty());
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn loads_inline_json_abi() {
+        let path = write_combined_json(
+            r#"{
+                "contracts": {
+                    "Test.sol:Test": {
+                        "abi": [],
+                        "bin": "6080",
+                        "bin-runtime": "6080",
+                        "linkReferences": {}
+                    }
+                }
+            }"#,
+        );
+
+        let contract = load(&path, "Test").expect("Could not load contract");
+        assert_eq!(contract.bytecode, Some(vec![0x60, 0x80]));
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn errors_when_contract_missing() {
+        let path = write_combined_json(r#"{"contracts": {}}"#);
+        let result = load(&path, "Missing");
+        assert!(result.is_err());
+        fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,118 @@
+//! Content-hash build cache to skip recompiling unchanged sources
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::command::{EvmVersion, Remapping};
+
+/// The subset of compile settings that affect a file's output and must
+/// therefore be folded into its cache digest alongside its content
+#[derive(Clone, Debug, Default)]
+pub struct CacheSettings {
+    pub solc_version: Option<String>,
+    pub optimize_runs: Option<u32>,
+    pub evm_version: Option<EvmVersion>,
+    pub remappings: Vec<Remapping>,
+    pub libraries: Vec<(String, String)>,
+}
+
+impl CacheSettings {
+    fn fingerprint(&self) -> String {
+        let remappings: Vec<String> = self.remappings.iter().map(Remapping::render).collect();
+        format!(
+            "{:?}|{:?}|{:?}|{:?}|{:?}",
+            self.solc_version, self.optimize_runs, self.evm_version, remappings, self.libraries
+        )
+    }
+}
+
+/// JSON-persisted cache mapping a source file path to a digest of its
+/// content plus the compile settings used to build it
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BuildCache {
+    digests: HashMap<String, String>,
+}
+
+impl BuildCache {
+    /// Load the cache file if it exists, or an empty cache otherwise
+    pub fn load<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the cache out to `path`
+    pub fn save<P>(&self, path: P) -> std::io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let json = serde_json::to_string_pretty(self).expect("Could not serialize build cache");
+        fs::write(path, json)
+    }
+
+    /// Hash `content` together with the settings that affect its compiled
+    /// output
+    pub fn digest(content: &[u8], settings: &CacheSettings) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(content);
+        hasher.update(settings.fingerprint().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// `true` if `path` was not last compiled with exactly `digest`
+    pub fn is_dirty(&self, path: &str, digest: &str) -> bool {
+        self.digests.get(path).map(String::as_str) != Some(digest)
+    }
+
+    /// Record that `path` was compiled with `digest`
+    pub fn mark_clean(&mut self, path: &str, digest: String) {
+        self.digests.insert(path.to_owned(), digest);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unknown_path_is_dirty() {
+        let cache = BuildCache::default();
+        assert!(cache.is_dirty("Test.sol", "abc123"));
+    }
+
+    #[test]
+    fn matching_digest_is_not_dirty() {
+        let mut cache = BuildCache::default();
+        cache.mark_clean("Test.sol", "abc123".to_owned());
+        assert!(!cache.is_dirty("Test.sol", "abc123"));
+    }
+
+    #[test]
+    fn changed_digest_is_dirty() {
+        let mut cache = BuildCache::default();
+        cache.mark_clean("Test.sol", "abc123".to_owned());
+        assert!(cache.is_dirty("Test.sol", "def456"));
+    }
+
+    #[test]
+    fn digest_changes_with_settings() {
+        let content = b"contract Test {}";
+        let a = BuildCache::digest(content, &CacheSettings::default());
+        let b = BuildCache::digest(
+            content,
+            &CacheSettings {
+                optimize_runs: Some(200),
+                ..Default::default()
+            },
+        );
+        assert_ne!(a, b);
+    }
+}
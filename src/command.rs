@@ -2,16 +2,99 @@
 
 use std::collections::HashMap;
 use std::default::Default;
+use std::fmt;
 use std::fmt::Debug;
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
 
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+use crate::error::SolcError;
+
+#[derive(Debug, Default)]
 pub struct CompileSettings {
     pub root: PathBuf,
     pub allow_paths: Vec<PathBuf>,
     pub output_dir: Option<PathBuf>,
     pub libraries_file: Option<PathBuf>,
+    pub optimize_runs: Option<u32>,
+    pub evm_version: Option<EvmVersion>,
+    pub remappings: Vec<Remapping>,
+}
+
+/// Solidity EVM version target for `--evm-version`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvmVersion {
+    Homestead,
+    TangerineWhistle,
+    SpuriousDragon,
+    Byzantium,
+    Constantinople,
+    Petersburg,
+    Istanbul,
+    Berlin,
+    London,
+    Paris,
+    Shanghai,
+    Cancun,
+}
+
+impl EvmVersion {
+    /// The spelling `solc` expects on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvmVersion::Homestead => "homestead",
+            EvmVersion::TangerineWhistle => "tangerineWhistle",
+            EvmVersion::SpuriousDragon => "spuriousDragon",
+            EvmVersion::Byzantium => "byzantium",
+            EvmVersion::Constantinople => "constantinople",
+            EvmVersion::Petersburg => "petersburg",
+            EvmVersion::Istanbul => "istanbul",
+            EvmVersion::Berlin => "berlin",
+            EvmVersion::London => "london",
+            EvmVersion::Paris => "paris",
+            EvmVersion::Shanghai => "shanghai",
+            EvmVersion::Cancun => "cancun",
+        }
+    }
+}
+
+impl fmt::Display for EvmVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A Solidity import remapping, rendered as the `[context:]prefix=target`
+/// syntax `solc` expects
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping {
+    pub context: Option<String>,
+    pub prefix: String,
+    pub target: String,
+}
+
+impl Remapping {
+    /// Create a new remapping. `context` scopes the remapping to imports
+    /// made from within that source directory; pass `None` for a
+    /// global remapping.
+    pub fn new(context: Option<&str>, prefix: &str, target: &str) -> Self {
+        Remapping {
+            context: context.map(|s| s.to_owned()),
+            prefix: prefix.to_owned(),
+            target: target.to_owned(),
+        }
+    }
+
+    /// Render as the `[context:]prefix=target` syntax `solc` expects
+    pub fn render(&self) -> String {
+        match &self.context {
+            Some(context) => format!("{}:{}={}", context, self.prefix, self.target),
+            None => format!("{}={}", self.prefix, self.target),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -51,7 +134,7 @@ pub enum CombinedOutput {
     UserDoc,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 /// Possible compiler outputs
 pub enum CompileOutput {
     None,
@@ -60,6 +143,15 @@ pub enum CompileOutput {
     CombinedJson(Vec<CombinedOutput>),
 }
 
+#[derive(Debug)]
+/// Result of running a `CompileCommand`: the process's exit status plus
+/// everything it wrote to stdout/stderr
+pub struct CompileRun {
+    pub status: ExitStatus,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+}
+
 #[derive(Debug)]
 /// Build up the compile command.
 /// All paths are relative to the root
@@ -68,6 +160,7 @@ pub struct CompileCommand {
     allow_paths: Vec<PathBuf>,
     /// dll -> path
     mappings: HashMap<String, PathBuf>,
+    remappings: Vec<Remapping>,
     // input
     source_files: Vec<PathBuf>,
     libraries: Option<PathBuf>,
@@ -81,6 +174,10 @@ pub struct CompileCommand {
     overwrite: bool,
     /// default: current directory
     output_dir: Option<PathBuf>,
+    optimize_runs: Option<u32>,
+    evm_version: Option<EvmVersion>,
+    /// path to the `solc` binary to invoke; defaults to `solc` on `PATH`
+    exe_path: Option<PathBuf>,
     command: Option<Command>,
 }
 
@@ -90,6 +187,7 @@ impl Default for CompileCommand {
             root: PathBuf::from("."),
             allow_paths: vec![],
             mappings: HashMap::new(),
+            remappings: vec![],
             source_files: vec![],
             libraries: None,
             link: false,
@@ -98,11 +196,69 @@ impl Default for CompileCommand {
             bin: None,
             overwrite: false,
             output_dir: Some(".".into()),
+            optimize_runs: None,
+            evm_version: None,
+            exe_path: None,
+            command: None,
+        }
+    }
+}
+
+impl Clone for CompileCommand {
+    /// Clone the builder's configuration. The built `Command`, if any, is not
+    /// carried over — `build()` will regenerate it from the cloned fields.
+    fn clone(&self) -> Self {
+        CompileCommand {
+            root: self.root.clone(),
+            allow_paths: self.allow_paths.clone(),
+            mappings: self.mappings.clone(),
+            remappings: self.remappings.clone(),
+            source_files: self.source_files.clone(),
+            libraries: self.libraries.clone(),
+            link: self.link,
+            outputs: self.outputs.clone(),
+            abi: self.abi,
+            bin: self.bin,
+            overwrite: self.overwrite,
+            output_dir: self.output_dir.clone(),
+            optimize_runs: self.optimize_runs,
+            evm_version: self.evm_version,
+            exe_path: self.exe_path.clone(),
             command: None,
         }
     }
 }
 
+/// A single named compilation profile for `CompileCommand::revisions`
+#[derive(Clone, Debug, Default)]
+pub struct Revision {
+    pub name: String,
+    pub optimize_runs: Option<u32>,
+    pub evm_version: Option<EvmVersion>,
+}
+
+impl Revision {
+    /// Create a new, unconfigured revision with the given name
+    pub fn new(name: &str) -> Self {
+        Revision {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Enable the optimizer with the given number of runs for this revision
+    pub fn optimize(mut self, runs: u32) -> Self {
+        self.optimize_runs = Some(runs);
+        self
+    }
+
+    /// Target a specific EVM version for this revision
+    pub fn evm_version(mut self, version: EvmVersion) -> Self {
+        self.evm_version = Some(version);
+        self
+    }
+}
+
 impl CompileCommand {
     /// Create a new `CompileCommand` with a given root
     pub fn new<P>(root: P) -> CompileCommand
@@ -121,7 +277,10 @@ impl CompileCommand {
         cmd.root = settings.root;
         cmd.output_dir = settings.output_dir;
         cmd.allow_paths = settings.allow_paths;
-        cmd.libraries = None;
+        cmd.libraries = settings.libraries_file;
+        cmd.optimize_runs = settings.optimize_runs;
+        cmd.evm_version = settings.evm_version;
+        cmd.remappings = settings.remappings;
 
         cmd
     }
@@ -208,6 +367,14 @@ impl CompileCommand {
         self
     }
 
+    /// Add a Solidity import remapping, rendered as `context:prefix=target`
+    /// (or just `prefix=target` when `context` is empty)
+    pub fn remapping(&mut self, context: &str, prefix: &str, target: &str) -> &mut Self {
+        let context = if context.is_empty() { None } else { Some(context) };
+        self.remappings.push(Remapping::new(context, prefix, target));
+        self
+    }
+
     /// Include libraries in compilation
     pub fn link(&mut self) -> &mut Self {
         self.link = true;
@@ -238,7 +405,58 @@ impl CompileCommand {
         self
     }
 
-    // TODO: add EPM package remapping
+    /// Enable the optimizer with the given number of runs
+    pub fn optimize(&mut self, runs: u32) -> &mut Self {
+        self.optimize_runs = Some(runs);
+        self
+    }
+
+    /// Target a specific EVM version
+    pub fn evm_version(&mut self, version: EvmVersion) -> &mut Self {
+        self.evm_version = Some(version);
+        self
+    }
+
+    /// Pin the `solc` binary to invoke, e.g. one resolved by
+    /// [`crate::resolver::resolve`]'s automatic version selection. Defaults
+    /// to `solc` on `PATH` when never called.
+    pub fn solc_path<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.exe_path = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Compile the same sources under each of the given named revisions
+    /// (e.g. optimizer on/off, or several `--evm-version` targets), writing
+    /// each revision's artifacts to `<output_dir>/<revision name>/` and
+    /// returning the per-revision results keyed by name.
+    pub fn revisions(&self, revisions: &[Revision]) -> io::Result<HashMap<String, CompileRun>> {
+        let mut results = HashMap::new();
+
+        for revision in revisions {
+            let mut cmd = self.clone();
+
+            let revision_dir = match &self.output_dir {
+                Some(dir) => dir.join(&revision.name),
+                None => PathBuf::from(&revision.name),
+            };
+            cmd.output_dir(revision_dir);
+
+            if let Some(runs) = revision.optimize_runs {
+                cmd.optimize(runs);
+            }
+            if let Some(version) = revision.evm_version {
+                cmd.evm_version(version);
+            }
+
+            let run = cmd.execute()?;
+            results.insert(revision.name.clone(), run);
+        }
+
+        Ok(results)
+    }
 
     /// Get the command that will be executed in the shell
     pub fn command_line(&self) -> String {
@@ -248,7 +466,8 @@ impl CompileCommand {
 
     /// Build up the shell command for compiling
     pub fn build(&mut self) {
-        let mut cmd = Command::new("solc");
+        let program: &Path = self.exe_path.as_deref().unwrap_or_else(|| Path::new("solc"));
+        let mut cmd = Command::new(program);
 
         cmd.current_dir(&self.root);
 
@@ -268,6 +487,10 @@ impl CompileCommand {
             cmd.arg(line);
         }
 
+        for remapping in &self.remappings {
+            cmd.arg(remapping.render());
+        }
+
         // output types
         // println!("OUTPUTS {:?}", self.outputs);
         match &self.outputs {
@@ -340,6 +563,17 @@ impl CompileCommand {
             cmd.arg("--overwrite");
         }
 
+        if let Some(runs) = self.optimize_runs {
+            cmd.arg("--optimize");
+            cmd.arg("--optimize-runs");
+            cmd.arg(runs.to_string());
+        }
+
+        if let Some(version) = self.evm_version {
+            cmd.arg("--evm-version");
+            cmd.arg(version.as_str());
+        }
+
         if let Some(ref dir) = self.output_dir {
             cmd.arg("-o");
             cmd.arg(dir.as_os_str());
@@ -348,18 +582,53 @@ impl CompileCommand {
         // sources
         cmd.args(&self.source_files);
 
-        println!("COMMAND: {:?}", cmd);
         self.command = Some(cmd);
     }
 
-    // TODO: create a CompileError
-    /// Execute the compile command in the shell
-    pub fn execute(&mut self) -> Option<&mut Command> {
+    /// Execute the compile command in the shell, capturing stdout/stderr.
+    ///
+    /// Both pipes are drained on their own thread so that large output
+    /// (e.g. `--combined-json` over many contracts) cannot deadlock the
+    /// child by filling one pipe's OS buffer while we block reading the
+    /// other.
+    pub fn execute(&mut self) -> io::Result<CompileRun> {
         if self.command.is_none() {
             self.build();
         }
 
-        self.command.as_mut()
+        let cmd = self.command.as_mut().expect("Command was not built");
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let mut child = cmd.spawn()?;
+
+        let mut stdout_pipe = child.stdout.take().expect("Child did not have a stdout handle");
+        let mut stderr_pipe = child.stderr.take().expect("Child did not have a stderr handle");
+
+        let stdout_handle = thread::spawn(move || {
+            let mut buf = Vec::new();
+            stdout_pipe
+                .read_to_end(&mut buf)
+                .expect("Problem reading child stdout");
+            buf
+        });
+        let stderr_handle = thread::spawn(move || {
+            let mut buf = Vec::new();
+            stderr_pipe
+                .read_to_end(&mut buf)
+                .expect("Problem reading child stderr");
+            buf
+        });
+
+        let status = child.wait()?;
+        let stdout = stdout_handle.join().expect("stdout reader thread panicked");
+        let stderr = stderr_handle.join().expect("stderr reader thread panicked");
+
+        Ok(CompileRun {
+            status,
+            stdout,
+            stderr,
+        })
     }
 
     /// Add the given path to the output dir
@@ -379,6 +648,238 @@ impl CompileCommand {
     }
 }
 
+/// Optimizer section of a `solc --standard-json` `settings` object
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonOptimizerSettings {
+    pub enabled: bool,
+    pub runs: u32,
+}
+
+/// A single entry of the `sources` map in a `solc --standard-json` input document
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonSource {
+    Content { content: String },
+    Urls { urls: Vec<String> },
+}
+
+/// `settings` object of a `solc --standard-json` input document
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimizer: Option<JsonOptimizerSettings>,
+    #[serde(rename = "evmVersion", skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remappings: Vec<String>,
+    /// file -> (library name -> address)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub libraries: HashMap<String, HashMap<String, String>>,
+    /// file -> (contract name -> list of selectors), "*" may be used for either key
+    #[serde(rename = "outputSelection", default)]
+    pub output_selection: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+/// Top-level input document consumed by `solc --standard-json`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StandardJsonInput {
+    pub language: String,
+    pub sources: HashMap<String, JsonSource>,
+    pub settings: JsonSettings,
+}
+
+/// `sourceLocation` field of a `solc --standard-json` error object
+#[derive(Clone, Debug, Deserialize)]
+pub struct JsonSourceLocation {
+    pub file: String,
+    pub start: i64,
+    pub end: i64,
+}
+
+/// One entry of the `errors` array in a `solc --standard-json` result
+#[derive(Clone, Debug, Deserialize)]
+pub struct StandardJsonError {
+    pub severity: String,
+    #[serde(rename = "errorCode")]
+    pub error_code: Option<u64>,
+    pub message: String,
+    #[serde(rename = "formattedMessage")]
+    pub formatted_message: Option<String>,
+    #[serde(rename = "sourceLocation")]
+    pub source_location: Option<JsonSourceLocation>,
+}
+
+/// `sources[file]` entry of a `solc --standard-json` result
+#[derive(Clone, Debug, Deserialize)]
+pub struct StandardJsonSourceInfo {
+    pub id: u32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct JsonBytecode {
+    pub object: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct JsonContractEvm {
+    pub bytecode: Option<JsonBytecode>,
+    #[serde(rename = "deployedBytecode")]
+    pub deployed_bytecode: Option<JsonBytecode>,
+}
+
+/// `contracts[file][name]` entry of a `solc --standard-json` result
+#[derive(Clone, Debug, Deserialize)]
+pub struct StandardJsonContract {
+    pub abi: Option<serde_json::Value>,
+    pub evm: Option<JsonContractEvm>,
+    pub metadata: Option<String>,
+}
+
+/// Deserialized result of a `solc --standard-json` invocation
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StandardJsonOutput {
+    #[serde(default)]
+    pub errors: Vec<StandardJsonError>,
+    #[serde(default)]
+    pub sources: HashMap<String, StandardJsonSourceInfo>,
+    /// file -> (contract name -> contract)
+    #[serde(default)]
+    pub contracts: HashMap<String, HashMap<String, StandardJsonContract>>,
+}
+
+/// Build up and run a `solc --standard-json` invocation.
+///
+/// Unlike `CompileCommand`, which drives the legacy positional flags, this
+/// pipes a single JSON input document to `solc`'s stdin and parses the JSON
+/// result back off stdout.
+#[derive(Debug)]
+pub struct StandardJsonCommand {
+    root: PathBuf,
+    input: StandardJsonInput,
+}
+
+impl StandardJsonCommand {
+    /// Create a new `StandardJsonCommand` with a given root
+    pub fn new<P>(root: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        StandardJsonCommand {
+            root: PathBuf::from(root.as_ref()),
+            input: StandardJsonInput {
+                language: "Solidity".to_owned(),
+                sources: HashMap::new(),
+                settings: JsonSettings::default(),
+            },
+        }
+    }
+
+    /// Add a source file by its in-memory content
+    pub fn add_source<P>(&mut self, path: P, content: impl Into<String>) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        let key = path.as_ref().to_string_lossy().into_owned();
+        self.input
+            .sources
+            .insert(key, JsonSource::Content { content: content.into() });
+        self
+    }
+
+    /// Enable the optimizer with the given number of runs
+    pub fn optimize(&mut self, runs: u32) -> &mut Self {
+        self.input.settings.optimizer = Some(JsonOptimizerSettings { enabled: true, runs });
+        self
+    }
+
+    /// Target a specific EVM version
+    pub fn evm_version(&mut self, version: &str) -> &mut Self {
+        self.input.settings.evm_version = Some(version.to_owned());
+        self
+    }
+
+    /// Add an import remapping, rendered as `prefix=target`
+    pub fn remapping(&mut self, prefix: &str, target: &str) -> &mut Self {
+        self.input
+            .settings
+            .remappings
+            .push(format!("{}={}", prefix, target));
+        self
+    }
+
+    /// Pin a library address for linking
+    pub fn library(&mut self, file: &str, name: &str, address: &str) -> &mut Self {
+        self.input
+            .settings
+            .libraries
+            .entry(file.to_owned())
+            .or_insert_with(HashMap::new)
+            .insert(name.to_owned(), address.to_owned());
+        self
+    }
+
+    /// Request outputs (e.g. `"abi"`, `"evm.bytecode.object"`) for a file/contract pair.
+    /// Use `"*"` for either `file` or `contract` to select all of them.
+    pub fn output_selection(&mut self, file: &str, contract: &str, selectors: &[&str]) -> &mut Self {
+        self.input
+            .settings
+            .output_selection
+            .entry(file.to_owned())
+            .or_insert_with(HashMap::new)
+            .entry(contract.to_owned())
+            .or_insert_with(Vec::new)
+            .extend(selectors.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Serialize the standard-json input document
+    pub fn input_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&self.input)
+    }
+
+    /// Run `solc --standard-json`, writing the input document to the child's
+    /// stdin and parsing the JSON result off stdout.
+    ///
+    /// Returns `SolcError::Process` if `solc` exits non-zero (its stderr is
+    /// included in the message) and `SolcError::Json` if stdout was not a
+    /// valid `StandardJsonOutput` document — a crashed or misconfigured
+    /// `solc` is never silently treated as "compiled with zero contracts".
+    pub fn execute(&self) -> Result<StandardJsonOutput, SolcError> {
+        let input = self
+            .input_json()
+            .expect("Could not serialize standard-json input");
+
+        let mut child = Command::new("solc")
+            .current_dir(&self.root)
+            .arg("--standard-json")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        child
+            .stdin
+            .take()
+            .expect("Child did not have a stdin handle")
+            .write_all(input.as_bytes())?;
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            return Err(SolcError::Process(io::Error::new(
+                io::ErrorKind::Other,
+                format!("solc --standard-json exited with {}: {}", output.status, stderr),
+            )));
+        }
+
+        serde_json::from_slice(&output.stdout).map_err(|e| SolcError::Json {
+            path: self.root.clone(),
+            message: e.to_string(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -560,4 +1061,110 @@ mod test {
 
     // test join_output_dir
     // test join_root
+
+    #[test]
+    fn test_optimize() {
+        let mut builder = CompileCommand::new("test");
+        builder.optimize(200).build();
+        let line = builder.command_line();
+        assert!(line.contains("--optimize"));
+        assert!(line.contains("--optimize-runs"));
+        assert!(line.contains("200"));
+    }
+
+    #[test]
+    fn test_evm_version() {
+        let mut builder = CompileCommand::new("test");
+        builder.evm_version(EvmVersion::Istanbul).build();
+        let line = builder.command_line();
+        assert!(line.contains("--evm-version"));
+        assert!(line.contains("istanbul"));
+    }
+
+    #[test]
+    fn test_remapping() {
+        let mut builder = CompileCommand::new("test");
+        builder
+            .remapping("", "@openzeppelin/", "lib/openzeppelin-contracts/")
+            .build();
+        assert!(builder
+            .command_line()
+            .contains("@openzeppelin/=lib/openzeppelin-contracts/"));
+    }
+
+    #[test]
+    fn test_remapping_with_context() {
+        let remapping = Remapping::new(Some("src"), "@openzeppelin/", "lib/openzeppelin-contracts/");
+        assert_eq!(
+            remapping.render(),
+            "src:@openzeppelin/=lib/openzeppelin-contracts/"
+        );
+    }
+
+    #[test]
+    fn from_settings_propagates_libraries_file() {
+        let settings = CompileSettings {
+            root: PathBuf::from("test"),
+            allow_paths: vec![],
+            output_dir: Some(PathBuf::from("output")),
+            libraries_file: Some(PathBuf::from("libs.txt")),
+            optimize_runs: None,
+            evm_version: None,
+            remappings: vec![],
+        };
+        let mut builder = CompileCommand::from_settings(settings);
+        builder.link().build();
+        assert!(builder.command_line().contains("--libraries"));
+    }
+
+    #[test]
+    fn revision_builds_with_name() {
+        let revision = Revision::new("optimized")
+            .optimize(200)
+            .evm_version(EvmVersion::Paris);
+        assert_eq!(revision.name, "optimized");
+        assert_eq!(revision.optimize_runs, Some(200));
+        assert_eq!(revision.evm_version, Some(EvmVersion::Paris));
+    }
+
+    #[test]
+    fn standard_json_input_has_solidity_language() {
+        let builder = StandardJsonCommand::new("test");
+        let json = builder.input_json().expect("Could not serialize input");
+        assert!(json.contains("\"language\":\"Solidity\""));
+    }
+
+    #[test]
+    fn standard_json_adds_source_content() {
+        let mut builder = StandardJsonCommand::new("test");
+        builder.add_source("Test.sol", "contract Test {}");
+        let json = builder.input_json().expect("Could not serialize input");
+        assert!(json.contains("Test.sol"));
+        assert!(json.contains("contract Test {}"));
+    }
+
+    #[test]
+    fn standard_json_sets_optimizer_and_evm_version() {
+        let mut builder = StandardJsonCommand::new("test");
+        builder.optimize(200).evm_version("istanbul");
+        let json = builder.input_json().expect("Could not serialize input");
+        assert!(json.contains("\"enabled\":true"));
+        assert!(json.contains("\"runs\":200"));
+        assert!(json.contains("\"evmVersion\":\"istanbul\""));
+    }
+
+    #[test]
+    fn standard_json_renders_remapping() {
+        let mut builder = StandardJsonCommand::new("test");
+        builder.remapping("@openzeppelin/", "lib/openzeppelin-contracts/");
+        let json = builder.input_json().expect("Could not serialize input");
+        assert!(json.contains("@openzeppelin/=lib/openzeppelin-contracts/"));
+    }
+
+    #[test]
+    fn standard_json_output_defaults_are_empty() {
+        let output = StandardJsonOutput::default();
+        assert!(output.errors.is_empty());
+        assert!(output.contracts.is_empty());
+    }
 }
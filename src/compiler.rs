@@ -2,12 +2,19 @@
 
 use ethereum_types::Address;
 use rustc_hex::FromHex;
+use semver::Version;
+use std::fs;
 use std::fs::File;
 use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 use std::str;
 
-use crate::command::CompileSettings;
+use crate::artifact::{self, Contract};
+use crate::cache::{BuildCache, CacheSettings};
+use crate::command::{CompileRun, CompileSettings, EvmVersion, Remapping};
+use crate::error::SolcError;
+use crate::layout::{PathStyle, ProjectPaths};
+use crate::resolver::{self, ResolveError, ResolvedGraph};
 use crate::{utils, CompileCommand};
 
 #[derive(Debug)]
@@ -29,6 +36,14 @@ pub struct Solc<'a> {
     lib_file: &'a str,
     /// library mappings for linking
     libraries: Vec<LibraryMapping>,
+    /// Solidity import remappings (e.g. `@openzeppelin/=lib/openzeppelin-contracts/`)
+    remappings: Vec<Remapping>,
+    optimize_runs: Option<u32>,
+    evm_version: Option<EvmVersion>,
+    /// solc version of the graph last passed to `command_for` or
+    /// `compile_if_dirty`, folded into the
+    /// build cache digest alongside the optimizer/EVM settings above
+    solc_version: Option<Version>,
     // TODO: add exe-path
 }
 
@@ -50,6 +65,10 @@ impl<'a> Solc<'a> {
             allow_paths: Vec::<String>::new(),
             lib_file: "libs.txt",
             libraries: Vec::new(),
+            remappings: Vec::new(),
+            optimize_runs: None,
+            evm_version: None,
+            solc_version: None,
         }
     }
 
@@ -63,6 +82,29 @@ impl<'a> Solc<'a> {
         self.output_dir.unwrap_or("")
     }
 
+    /// Creates a new `Solc` pre-configured for a conventional project layout
+    /// (Hardhat's `contracts/`/`artifacts/`/`node_modules/`, or Dapptools'
+    /// `src/`/`out/`/`lib/`), auto-populating `output_dir` and `allow_paths`
+    /// from `style` so callers don't have to know every path up front.
+    pub fn with_layout<P>(root: P, style: PathStyle) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        let mut solc = Solc::new(root);
+        solc.output_dir = Some(style.artifacts_dir());
+        solc.allow_paths = vec![
+            style.sources_dir().to_owned(),
+            style.libraries_dir().to_owned(),
+        ];
+        solc
+    }
+
+    /// Recursively collect every `.sol` file under this project's
+    /// conventional sources directory for `style`
+    pub fn project_sources(&self, style: PathStyle) -> Vec<PathBuf> {
+        ProjectPaths::new(self.root(), style).source_files()
+    }
+
     /// Add library address for linking
     pub fn add_library_address(&mut self, name: &str, address: Address) {
         self.libraries.push(LibraryMapping {
@@ -71,90 +113,255 @@ impl<'a> Solc<'a> {
         });
     }
 
+    /// Add a Solidity import remapping (e.g. `@openzeppelin/=lib/openzeppelin-contracts/`)
+    pub fn add_remapping(&mut self, from: &str, to: &str) {
+        self.remappings.push(Remapping::new(None, from, to));
+    }
+
+    /// Enable the optimizer with the given number of runs for every compile
+    pub fn optimize(&mut self, runs: u32) {
+        self.optimize_runs = Some(runs);
+    }
+
+    /// Target a specific EVM version for every compile
+    pub fn evm_version(&mut self, version: EvmVersion) {
+        self.evm_version = Some(version);
+    }
+
     /// Write out the library file from the libraries
     // TODO: don't actually save to a file?
-    pub fn prepare_link(&self) {
-        if let Some(dir) = self.output_dir {
-            match utils::join_path(dir, self.lib_file) {
-                Ok(ref path) => {
-                    // want <root>/<path>
-                    let mut full_path = PathBuf::from(self.root());
-                    full_path.push(path);
-                    let mut lib_file = File::create(full_path).expect("Could not create libs file");
-
-                    // write each library to the file
-                    for lib in &self.libraries {
-                        if let Err(e) = writeln!(lib_file, "{}:{:?}", lib.name, lib.address) {
-                            eprintln!("Couldn't write to library file: {}", e);
-                        }
-                    }
+    pub fn prepare_link(&self) -> Result<(), SolcError> {
+        let dir = self.output_dir.ok_or(SolcError::MissingOutputDir)?;
+        let path =
+            utils::join_path(dir, self.lib_file).map_err(|e| SolcError::Link(e.to_owned()))?;
+
+        // want <root>/<path>
+        let mut full_path = PathBuf::from(self.root());
+        full_path.push(path);
+        let mut lib_file = File::create(&full_path).map_err(|source| SolcError::Io {
+            path: full_path.clone(),
+            source,
+        })?;
+
+        // write each library to the file
+        for lib in &self.libraries {
+            writeln!(lib_file, "{}:{:?}", lib.name, lib.address).map_err(|source| {
+                SolcError::Io {
+                    path: full_path.clone(),
+                    source,
                 }
-                // TODO: deal with this properly
-                Err(_) => panic!("Problem with lib file path"),
-            } // end join_path
-        } // end self.output_dir
+            })?;
+        }
+
+        Ok(())
     }
 
     // load from <root>/<output_dir>/<name>
     // only loads LINKED bytecode
-    // TODO: return Result
-    pub fn load_bytecode(&self, name: &str) -> Vec<u8> {
+    pub fn load_bytecode(&self, name: &str) -> Result<Vec<u8>, SolcError> {
         match self.output_dir {
             Some(ref dir) => {
                 let bytecode_path: PathBuf = [self.root(), dir, name].iter().collect();
-                println!("bytecode at: {:?}", bytecode_path);
-                // TODO: use combinators
                 let path = format!("{}", bytecode_path.display());
-                let bytes = load_bytes(&path[..]);
-                let code = str::from_utf8(&bytes[..]).unwrap();
-                // println!("CODE: {}", code);
-                // bytecode_path.as_path()
-                code.from_hex().unwrap()
-                // code
+                let bytes = load_bytes(&path[..])?;
+                let code = str::from_utf8(&bytes[..]).map_err(|source| SolcError::Utf8 {
+                    path: bytecode_path.clone(),
+                    source,
+                })?;
+                code.from_hex().map_err(|source| SolcError::HexDecode {
+                    path: bytecode_path,
+                    source,
+                })
             }
-            None => panic!("No output path set"),
+            None => Err(SolcError::MissingOutputDir),
         }
     }
 
     /// Load a given ABI file from the output directory
     /// name is the file name
-    pub fn load_abi(&self, name: &str) -> Vec<u8> {
+    pub fn load_abi(&self, name: &str) -> Result<Vec<u8>, SolcError> {
         match self.output_dir {
             Some(ref dir) => {
                 let abi_path: PathBuf = [self.root(), dir, name].iter().collect();
-                let path: &str = abi_path.to_str().unwrap();
+                let path = abi_path
+                    .to_str()
+                    .ok_or_else(|| SolcError::InvalidPath(abi_path.clone()))?;
                 load_bytes(path)
             }
-            None => panic!("No output path set"),
+            None => Err(SolcError::MissingOutputDir),
+        }
+    }
+
+    /// Load a contract's ABI, bytecode, and unlinked library references out
+    /// of `<output_dir>/combined.json`, the file `solc --combined-json -o`
+    /// writes
+    pub fn load_contract(&self, name: &str) -> Result<Contract, SolcError> {
+        let dir = self.output_dir.ok_or(SolcError::MissingOutputDir)?;
+        let path: PathBuf = [self.root(), dir, "combined.json"].iter().collect();
+        artifact::load(&path, name)
+    }
+
+    /// Path to the JSON build cache file, `<output_dir>/cache.json`
+    fn cache_path(&self) -> Option<PathBuf> {
+        self.output_dir.map(|dir| {
+            let mut path = PathBuf::from(self.root());
+            path.push(dir);
+            path.push("cache.json");
+            path
+        })
+    }
+
+    fn cache_settings(&self) -> CacheSettings {
+        CacheSettings {
+            solc_version: self.solc_version.as_ref().map(Version::to_string),
+            optimize_runs: self.optimize_runs,
+            evm_version: self.evm_version,
+            remappings: self.remappings.clone(),
+            libraries: self
+                .libraries
+                .iter()
+                .map(|lib| (lib.name.clone(), format!("{:?}", lib.address)))
+                .collect(),
         }
     }
 
+    /// `true` if `name` (a source file path relative to `root`) has not
+    /// been compiled with its current content and settings before
+    pub fn is_dirty(&self, name: &str) -> bool {
+        let cache_path = match self.cache_path() {
+            Some(path) => path,
+            None => return true,
+        };
+
+        let source_path: PathBuf = [self.root(), name].iter().collect();
+        let content = match fs::read(&source_path) {
+            Ok(content) => content,
+            Err(_) => return true,
+        };
+
+        let digest = BuildCache::digest(&content, &self.cache_settings());
+        BuildCache::load(&cache_path).is_dirty(name, &digest)
+    }
+
+    /// Record that `name` was compiled with its current content and
+    /// settings, so a subsequent `is_dirty` call returns `false` until
+    /// either changes
+    pub fn mark_compiled(&self, name: &str) -> std::io::Result<()> {
+        let cache_path = match self.cache_path() {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let source_path: PathBuf = [self.root(), name].iter().collect();
+        let content = fs::read(&source_path)?;
+
+        let digest = BuildCache::digest(&content, &self.cache_settings());
+        let mut cache = BuildCache::load(&cache_path);
+        cache.mark_clean(name, digest);
+        cache.save(&cache_path)
+    }
+
     /// Generate a `CompileCommand` from the compiler for building
     /// up the compilation.
-    pub fn command(&self) -> CompileCommand {
-        // TODO: add allow_paths here
+    pub fn command(&self) -> Result<CompileCommand, SolcError> {
+        if self.output_dir.is_none() {
+            return Err(SolcError::MissingOutputDir);
+        }
+
         let settings = CompileSettings {
             root: PathBuf::from(self.root()),
-            allow_paths: vec![],
+            allow_paths: self.allow_paths.iter().map(PathBuf::from).collect(),
             output_dir: Some(PathBuf::from(self.output_dir())),
             libraries_file: Some(PathBuf::from(self.lib_file)),
+            remappings: self.remappings.clone(),
+            optimize_runs: self.optimize_runs,
+            evm_version: self.evm_version,
         };
-        CompileCommand::from_settings(settings)
+        Ok(CompileCommand::from_settings(settings))
     }
-}
 
-// TODO: return Result
-fn load_bytes(path: &str) -> Vec<u8> {
-    match File::open(path) {
-        Ok(file) => {
-            let mut reader = BufReader::new(file);
-            let mut contents: Vec<u8> = Vec::new();
+    /// Discover and order every source reachable from `entry` by walking
+    /// `import` statements, and pick the highest installed `solc` version
+    /// satisfying the combined `pragma solidity` constraint.
+    pub fn resolve(&self, entry: &Path) -> Result<ResolvedGraph, ResolveError> {
+        let allow_paths: Vec<PathBuf> = self.allow_paths.iter().map(PathBuf::from).collect();
+        resolver::resolve(
+            entry,
+            &self.remappings,
+            &allow_paths,
+            &resolver::installed_versions(),
+        )
+    }
 
-            match reader.read_to_end(&mut contents) {
-                Ok(_) => contents,
-                Err(e) => panic!("Problem reading file {}", e),
+    /// Build a `CompileCommand` that compiles exactly the files discovered
+    /// by `resolve`, requesting bytecode output and pinned to the `solc`
+    /// binary `resolve` chose for `graph`. Also records `graph.version` so
+    /// it is folded into the build cache digest alongside the optimizer and
+    /// EVM-version settings.
+    pub fn command_for(&mut self, graph: &ResolvedGraph) -> Result<CompileCommand, SolcError> {
+        self.solc_version = Some(graph.version.clone());
+
+        let mut cmd = self.command()?;
+        cmd.bin();
+        if let Some(path) = resolver::binary_path(&graph.version) {
+            cmd.solc_path(path);
+        }
+        for file in &graph.files {
+            cmd.add_source(file);
+        }
+        Ok(cmd)
+    }
+
+    /// Compile every file in `graph` via `command_for`, but skip invoking
+    /// `solc` entirely when none of them are dirty (per [`Solc::is_dirty`]),
+    /// returning `None` in that case. Marks every file clean on success.
+    pub fn compile_if_dirty(&mut self, graph: &ResolvedGraph) -> Result<Option<CompileRun>, SolcError> {
+        // Record the resolved version before the dirty check, not just in
+        // `command_for` below: a fresh `Solc` (i.e. a new process) has no
+        // `solc_version` yet, so checking first would digest without it
+        // while `mark_compiled` always digests with it, and every fresh
+        // invocation would report dirty and recompile.
+        self.solc_version = Some(graph.version.clone());
+
+        let names: Vec<String> = graph.files.iter().map(|f| f.display().to_string()).collect();
+
+        if !names.iter().any(|name| self.is_dirty(name)) {
+            return Ok(None);
+        }
+
+        let run = self.command_for(graph)?.execute()?;
+
+        if run.status.success() {
+            for name in &names {
+                self.mark_compiled(name)?;
             }
         }
-        Err(e) => panic!("Could not open file {}: {}", path, e),
+
+        Ok(Some(run))
+    }
+
+    /// Compile the configured sources once per named revision (e.g.
+    /// optimizer on/off, or several `--evm-version` targets), returning the
+    /// per-revision `CompileRun` results keyed by revision name.
+    pub fn revisions(
+        &self,
+        revisions: &[crate::command::Revision],
+    ) -> Result<std::collections::HashMap<String, crate::command::CompileRun>, SolcError> {
+        Ok(self.command()?.revisions(revisions)?)
     }
 }
+
+fn load_bytes(path: &str) -> Result<Vec<u8>, SolcError> {
+    let file = File::open(path).map_err(|source| SolcError::Io {
+        path: PathBuf::from(path),
+        source,
+    })?;
+    let mut reader = BufReader::new(file);
+    let mut contents: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut contents).map_err(|source| SolcError::Io {
+        path: PathBuf::from(path),
+        source,
+    })?;
+
+    Ok(contents)
+}
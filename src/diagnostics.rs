@@ -0,0 +1,138 @@
+//! Typed diagnostics for compiler output
+
+use std::fmt;
+
+use crate::command::StandardJsonError;
+
+/// Severity classification for a diagnostic, mirroring `solc`'s own
+/// Error/Warning/Info kind-classification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    /// Parse the `severity` field of a `solc` JSON error object
+    pub fn parse(value: &str) -> Option<Severity> {
+        match value {
+            "error" => Some(Severity::Error),
+            "warning" => Some(Severity::Warning),
+            "info" => Some(Severity::Info),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Byte-offset span of a diagnostic within a source file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: String,
+    pub start: i64,
+    pub end: i64,
+}
+
+/// A single diagnostic emitted by the compiler
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub error_code: Option<u64>,
+    pub message: String,
+    pub formatted_message: Option<String>,
+    pub source_location: Option<SourceLocation>,
+}
+
+impl Diagnostic {
+    /// `true` if this diagnostic should be treated as a hard compile error
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl From<&StandardJsonError> for Diagnostic {
+    fn from(error: &StandardJsonError) -> Self {
+        Diagnostic {
+            severity: Severity::parse(&error.severity).unwrap_or(Severity::Error),
+            error_code: error.error_code,
+            message: error.message.clone(),
+            formatted_message: error.formatted_message.clone(),
+            source_location: error.source_location.as_ref().map(|loc| SourceLocation {
+                file: loc.file.clone(),
+                start: loc.start,
+                end: loc.end,
+            }),
+        }
+    }
+}
+
+/// Set of non-fatal diagnostics (warnings/info) carried alongside a
+/// successful compile run
+#[derive(Clone, Debug, Default)]
+pub struct CompileOutputSet {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Parse `solc`'s JSON error objects into typed `Diagnostic`s, and split
+/// them into a successful `CompileOutputSet` (only warnings/info) or an
+/// error result (at least one hard error) depending on severity.
+pub fn classify(errors: &[StandardJsonError]) -> Result<CompileOutputSet, Vec<Diagnostic>> {
+    let diagnostics: Vec<Diagnostic> = errors.iter().map(Diagnostic::from).collect();
+
+    if diagnostics.iter().any(Diagnostic::is_error) {
+        Err(diagnostics)
+    } else {
+        Ok(CompileOutputSet { diagnostics })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::command::StandardJsonError;
+
+    fn error(severity: &str) -> StandardJsonError {
+        StandardJsonError {
+            severity: severity.to_owned(),
+            error_code: Some(1234),
+            message: "test message".to_owned(),
+            formatted_message: None,
+            source_location: None,
+        }
+    }
+
+    #[test]
+    fn parses_known_severities() {
+        assert_eq!(Severity::parse("error"), Some(Severity::Error));
+        assert_eq!(Severity::parse("warning"), Some(Severity::Warning));
+        assert_eq!(Severity::parse("info"), Some(Severity::Info));
+        assert_eq!(Severity::parse("bogus"), None);
+    }
+
+    #[test]
+    fn classify_returns_ok_for_warnings_only() {
+        let errors = vec![error("warning")];
+        let result = classify(&errors);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn classify_returns_err_if_any_error() {
+        let errors = vec![error("warning"), error("error")];
+        let result = classify(&errors);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().len(), 2);
+    }
+}
@@ -0,0 +1,64 @@
+//! Error type for the public API
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Errors produced by the public `solc-rs` API
+#[derive(Debug)]
+pub enum SolcError {
+    /// An IO operation failed on the given path
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A hex-encoded bytecode file could not be decoded
+    HexDecode {
+        path: PathBuf,
+        source: rustc_hex::FromHexError,
+    },
+    /// A file's contents were not valid UTF-8
+    Utf8 {
+        path: PathBuf,
+        source: std::str::Utf8Error,
+    },
+    /// A path could not be converted to a UTF-8 string
+    InvalidPath(PathBuf),
+    /// No output directory has been configured
+    MissingOutputDir,
+    /// A problem preparing or writing the library linking file
+    Link(String),
+    /// A `solc` invocation failed at the process level
+    Process(std::io::Error),
+    /// A JSON document (combined-JSON output or an embedded ABI) could not
+    /// be parsed
+    Json { path: PathBuf, message: String },
+}
+
+impl fmt::Display for SolcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolcError::Io { path, source } => write!(f, "IO error at {:?}: {}", path, source),
+            SolcError::HexDecode { path, source } => {
+                write!(f, "Could not decode hex in {:?}: {}", path, source)
+            }
+            SolcError::Utf8 { path, source } => {
+                write!(f, "File {:?} was not valid UTF-8: {}", path, source)
+            }
+            SolcError::InvalidPath(path) => write!(f, "Path {:?} is not valid UTF-8", path),
+            SolcError::MissingOutputDir => write!(f, "No output directory has been configured"),
+            SolcError::Link(message) => write!(f, "Problem preparing link: {}", message),
+            SolcError::Process(source) => write!(f, "Problem running solc: {}", source),
+            SolcError::Json { path, message } => {
+                write!(f, "Could not parse JSON in {:?}: {}", path, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolcError {}
+
+impl From<std::io::Error> for SolcError {
+    fn from(source: std::io::Error) -> Self {
+        SolcError::Process(source)
+    }
+}
@@ -0,0 +1,127 @@
+//! Convention-based project layout discovery (Hardhat/Dapptools styles)
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A well-known project layout convention
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathStyle {
+    /// `contracts/`, `artifacts/`, `node_modules/`, `cache/`
+    HardHat,
+    /// `src/`, `out/`, `lib/`, `out/cache/`
+    Dapptools,
+}
+
+impl PathStyle {
+    /// Directory (relative to the project root) holding `.sol` sources
+    pub fn sources_dir(&self) -> &'static str {
+        match self {
+            PathStyle::HardHat => "contracts",
+            PathStyle::Dapptools => "src",
+        }
+    }
+
+    /// Directory (relative to the project root) compile artifacts go in
+    pub fn artifacts_dir(&self) -> &'static str {
+        match self {
+            PathStyle::HardHat => "artifacts",
+            PathStyle::Dapptools => "out",
+        }
+    }
+
+    /// Directory (relative to the project root) external libraries live in
+    pub fn libraries_dir(&self) -> &'static str {
+        match self {
+            PathStyle::HardHat => "node_modules",
+            PathStyle::Dapptools => "lib",
+        }
+    }
+
+    /// Directory (relative to the project root) the build cache lives in
+    pub fn cache_dir(&self) -> &'static str {
+        match self {
+            PathStyle::HardHat => "cache",
+            PathStyle::Dapptools => "out/cache",
+        }
+    }
+}
+
+/// Source/artifact/library/cache directories derived from a single
+/// canonicalized project root
+#[derive(Clone, Debug)]
+pub struct ProjectPaths {
+    pub root: PathBuf,
+    pub sources: PathBuf,
+    pub artifacts: PathBuf,
+    pub libraries: PathBuf,
+    pub cache: PathBuf,
+}
+
+impl ProjectPaths {
+    /// Derive conventional paths for `root` under the given `style`.
+    /// `root` is canonicalized when it exists and used as given otherwise.
+    pub fn new<P>(root: P, style: PathStyle) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        let root = root.as_ref();
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_owned());
+        ProjectPaths {
+            sources: root.join(style.sources_dir()),
+            artifacts: root.join(style.artifacts_dir()),
+            libraries: root.join(style.libraries_dir()),
+            cache: root.join(style.cache_dir()),
+            root,
+        }
+    }
+
+    /// Recursively collect every `.sol` file under `sources`
+    pub fn source_files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        collect_sol_files(&self.sources, &mut files);
+        files
+    }
+}
+
+fn collect_sol_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_sol_files(&path, files);
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("sol") {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hardhat_uses_contracts_and_artifacts() {
+        let paths = ProjectPaths::new("/project", PathStyle::HardHat);
+        assert_eq!(paths.sources, PathBuf::from("/project/contracts"));
+        assert_eq!(paths.artifacts, PathBuf::from("/project/artifacts"));
+        assert_eq!(paths.libraries, PathBuf::from("/project/node_modules"));
+    }
+
+    #[test]
+    fn dapptools_uses_src_and_out() {
+        let paths = ProjectPaths::new("/project", PathStyle::Dapptools);
+        assert_eq!(paths.sources, PathBuf::from("/project/src"));
+        assert_eq!(paths.artifacts, PathBuf::from("/project/out"));
+        assert_eq!(paths.libraries, PathBuf::from("/project/lib"));
+    }
+
+    #[test]
+    fn source_files_is_empty_for_missing_directory() {
+        let paths = ProjectPaths::new("/does/not/exist", PathStyle::HardHat);
+        assert!(paths.source_files().is_empty());
+    }
+}
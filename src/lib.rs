@@ -1,35 +1,17 @@
 //! Call the Solidity compiler
 
-use std::fmt::Debug;
-use std::fs::File;
-use std::io::{BufReader, Read};
-use std::path::Path;
-
 use crate::command::CompileCommand;
 
+pub mod artifact;
+pub mod cache;
 pub mod command;
 pub mod compiler;
+pub mod diagnostics;
+pub mod error;
+pub mod layout;
+pub mod resolver;
 mod utils;
 
-// TODO: return Result
-fn load_bytes<P>(path: P) -> Vec<u8>
-where
-    P: AsRef<Path> + Debug,
-{
-    match File::open(&path) {
-        Ok(file) => {
-            let mut reader = BufReader::new(file);
-            let mut contents: Vec<u8> = Vec::new();
-
-            match reader.read_to_end(&mut contents) {
-                Ok(_) => contents,
-                Err(e) => panic!("Problem reading file {}", e),
-            }
-        }
-        Err(e) => panic!("Could not open file {:?}: {}", path, e),
-    }
-}
-
 #[cfg(test)]
 mod test {
     use crate::compiler::Solc;
@@ -41,9 +23,8 @@ mod test {
         let compiler = Solc::new("test");
         compiler
             .command()
+            .expect("No output directory set")
             .execute()
-            .expect("No command")
-            .output()
             .expect("Problem executing command");
     }
 
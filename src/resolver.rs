@@ -0,0 +1,306 @@
+//! Import-graph resolution and automatic solc version selection
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use semver::{Version, VersionReq};
+
+use crate::command::Remapping;
+
+/// Topologically sorted source files (dependencies before dependents) plus
+/// the solc version chosen to satisfy every file's `pragma solidity`
+/// constraint
+#[derive(Clone, Debug)]
+pub struct ResolvedGraph {
+    pub files: Vec<PathBuf>,
+    pub version: Version,
+}
+
+#[derive(Debug)]
+pub enum ResolveError {
+    Io(PathBuf, std::io::Error),
+    /// A cycle was found; the path walks the import chain from the entry
+    /// file and ends with the file that was reached a second time
+    Cycle(Vec<PathBuf>),
+    /// No installed solc version satisfies the combined pragma constraint
+    NoVersionSatisfies(VersionReq),
+    UnresolvedImport { importer: PathBuf, import: String },
+    /// Two files' `pragma solidity` constraints could not be combined into
+    /// a single requirement
+    InvalidPragma(String),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResolveError::Io(path, e) => write!(f, "Problem reading {:?}: {}", path, e),
+            ResolveError::Cycle(path) => write!(f, "Import cycle detected: {:?}", path),
+            ResolveError::NoVersionSatisfies(req) => {
+                write!(f, "No installed solc version satisfies {}", req)
+            }
+            ResolveError::UnresolvedImport { importer, import } => write!(
+                f,
+                "Could not resolve import {:?} from {:?}",
+                import, importer
+            ),
+            ResolveError::InvalidPragma(message) => {
+                write!(f, "Could not combine pragma solidity constraints: {}", message)
+            }
+        }
+    }
+}
+
+/// Pull the `import "..."` / `import {X} from "..."` paths out of Solidity
+/// source text. Only single-line import statements are handled.
+fn parse_imports(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("import"))
+        .filter_map(extract_quoted)
+        .collect()
+}
+
+fn extract_quoted(line: &str) -> Option<String> {
+    let start = line.find(|c| c == '"' || c == '\'')?;
+    let quote = line.as_bytes()[start] as char;
+    let rest = &line[start + 1..];
+    let end = rest.find(quote)?;
+    Some(rest[..end].to_owned())
+}
+
+/// Parse a `pragma solidity <range>;` line into a semver `VersionReq`.
+///
+/// Solidity pragmas space-separate their comparators (`>=0.7.0 <0.9.0`),
+/// while `semver::VersionReq` requires commas, so the comparators are
+/// re-joined with `, ` before parsing.
+fn parse_pragma(source: &str) -> Option<VersionReq> {
+    source.lines().map(str::trim).find_map(|line| {
+        let rest = line.strip_prefix("pragma solidity")?;
+        let rest = rest.trim().trim_end_matches(';').trim();
+        let normalized = rest.split_whitespace().collect::<Vec<_>>().join(", ");
+        VersionReq::parse(&normalized).ok()
+    })
+}
+
+/// Combine two `pragma solidity` constraints into a single requirement that
+/// a version must satisfy both to match
+fn intersect(a: &VersionReq, b: &VersionReq) -> Result<VersionReq, ResolveError> {
+    let combined = format!("{}, {}", a, b);
+    VersionReq::parse(&combined).map_err(|e| {
+        ResolveError::InvalidPragma(format!("could not combine `{}` and `{}`: {}", a, b, e))
+    })
+}
+
+/// Resolve a single import path against the configured remappings, the
+/// importer's directory, and the allowed include paths, in that order
+fn resolve_import(
+    import: &str,
+    importer_dir: &Path,
+    remappings: &[Remapping],
+    allow_paths: &[PathBuf],
+) -> Option<PathBuf> {
+    for remapping in remappings {
+        if let Some(rest) = import.strip_prefix(remapping.prefix.as_str()) {
+            let candidate = PathBuf::from(&remapping.target).join(rest);
+            if candidate.exists() {
+                return Some(candidate);
+            }
+        }
+    }
+
+    let relative = importer_dir.join(import);
+    if relative.exists() {
+        return Some(relative);
+    }
+
+    for root in allow_paths {
+        let candidate = root.join(import);
+        if candidate.exists() {
+            return Some(candidate);
+        }
+    }
+
+    None
+}
+
+/// Discover `solc` versions installed via the `svm` convention used by
+/// foundry/svm-rs: `~/.svm/<version>/solc-<version>`
+pub fn installed_versions() -> Vec<Version> {
+    let svm_root = match dirs::home_dir() {
+        Some(home) => home.join(".svm"),
+        None => return Vec::new(),
+    };
+
+    fs::read_dir(&svm_root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().and_then(|n| Version::parse(n).ok()))
+        .collect()
+}
+
+/// Path to the `solc` binary for `version` under the `svm` convention used
+/// by foundry/svm-rs: `~/.svm/<version>/solc-<version>`. Returns `None` if
+/// the home directory cannot be determined.
+pub fn binary_path(version: &Version) -> Option<PathBuf> {
+    let svm_root = dirs::home_dir()?.join(".svm");
+    Some(svm_root.join(version.to_string()).join(format!("solc-{}", version)))
+}
+
+/// Walk the import graph starting at `entry`, returning every reachable
+/// source file in topological order (dependencies first) plus the solc
+/// version chosen to satisfy the combined `pragma solidity` constraint.
+pub fn resolve(
+    entry: &Path,
+    remappings: &[Remapping],
+    allow_paths: &[PathBuf],
+    installed: &[Version],
+) -> Result<ResolvedGraph, ResolveError> {
+    let mut visited = HashSet::new();
+    let mut on_stack = Vec::new();
+    let mut order = Vec::new();
+    let mut combined_req: Option<VersionReq> = None;
+
+    visit(
+        entry,
+        remappings,
+        allow_paths,
+        &mut visited,
+        &mut on_stack,
+        &mut order,
+        &mut combined_req,
+    )?;
+
+    let version = match combined_req {
+        Some(ref req) => installed
+            .iter()
+            .filter(|v| req.matches(v))
+            .max()
+            .cloned()
+            .ok_or_else(|| ResolveError::NoVersionSatisfies(req.clone()))?,
+        None => installed
+            .iter()
+            .max()
+            .cloned()
+            .ok_or_else(|| ResolveError::NoVersionSatisfies(VersionReq::STAR))?,
+    };
+
+    Ok(ResolvedGraph {
+        files: order,
+        version,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn visit(
+    file: &Path,
+    remappings: &[Remapping],
+    allow_paths: &[PathBuf],
+    visited: &mut HashSet<PathBuf>,
+    on_stack: &mut Vec<PathBuf>,
+    order: &mut Vec<PathBuf>,
+    combined_req: &mut Option<VersionReq>,
+) -> Result<(), ResolveError> {
+    let canonical = file
+        .canonicalize()
+        .map_err(|e| ResolveError::Io(file.to_owned(), e))?;
+
+    if on_stack.contains(&canonical) {
+        let mut cycle = on_stack.clone();
+        cycle.push(canonical);
+        return Err(ResolveError::Cycle(cycle));
+    }
+    if visited.contains(&canonical) {
+        return Ok(());
+    }
+
+    on_stack.push(canonical.clone());
+
+    let source =
+        fs::read_to_string(&canonical).map_err(|e| ResolveError::Io(canonical.clone(), e))?;
+
+    if let Some(req) = parse_pragma(&source) {
+        *combined_req = Some(match combined_req.take() {
+            Some(existing) => intersect(&existing, &req)?,
+            None => req,
+        });
+    }
+
+    let importer_dir = canonical.parent().unwrap_or_else(|| Path::new("."));
+    for import in parse_imports(&source) {
+        match resolve_import(&import, importer_dir, remappings, allow_paths) {
+            Some(resolved) => visit(
+                &resolved,
+                remappings,
+                allow_paths,
+                visited,
+                on_stack,
+                order,
+                combined_req,
+            )?,
+            None => {
+                return Err(ResolveError::UnresolvedImport {
+                    importer: canonical.clone(),
+                    import,
+                })
+            }
+        }
+    }
+
+    on_stack.pop();
+    visited.insert(canonical.clone());
+    order.push(canonical);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_double_quoted_import() {
+        let source = r#"import "./Base.sol";"#;
+        assert_eq!(parse_imports(source), vec!["./Base.sol".to_owned()]);
+    }
+
+    #[test]
+    fn parses_named_import() {
+        let source = r#"import {Base} from "./Base.sol";"#;
+        assert_eq!(parse_imports(source), vec!["./Base.sol".to_owned()]);
+    }
+
+    #[test]
+    fn ignores_non_import_lines() {
+        let source = "contract Test {}\n// import \"nope\"";
+        assert!(parse_imports(source).is_empty());
+    }
+
+    #[test]
+    fn parses_pragma_range() {
+        let source = "pragma solidity ^0.8.0;\ncontract Test {}";
+        let req = parse_pragma(source).expect("Expected a pragma");
+        assert!(req.matches(&Version::parse("0.8.19").unwrap()));
+        assert!(!req.matches(&Version::parse("0.7.6").unwrap()));
+    }
+
+    #[test]
+    fn parses_space_separated_pragma_range() {
+        let source = "pragma solidity >=0.7.0 <0.9.0;\ncontract Test {}";
+        let req = parse_pragma(source).expect("Expected a pragma");
+        assert!(req.matches(&Version::parse("0.8.19").unwrap()));
+        assert!(!req.matches(&Version::parse("0.9.0").unwrap()));
+    }
+
+    #[test]
+    fn intersect_combines_both_constraints() {
+        let a = VersionReq::parse(">=0.8.0").unwrap();
+        let b = VersionReq::parse("<0.9.0").unwrap();
+        let combined = intersect(&a, &b).expect("Expected constraints to combine");
+        assert!(combined.matches(&Version::parse("0.8.19").unwrap()));
+        assert!(!combined.matches(&Version::parse("0.9.0").unwrap()));
+    }
+}